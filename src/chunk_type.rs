@@ -1,12 +1,77 @@
 use std::cmp::{Eq, PartialEq};
 use std::convert::TryFrom;
+use std::error::Error as StdError;
+use std::fmt;
 use std::fmt::Display;
 use std::str::FromStr;
 
 pub type Error = Box<dyn std::error::Error>;
 pub type Result<T> = std::result::Result<T, Error>;
 
+/// Errors produced while parsing or validating a [`ChunkType`].
 #[derive(Debug)]
+pub enum ChunkTypeError {
+    /// The input was not exactly 4 bytes long.
+    WrongLength { found: usize },
+    /// A byte was not one of the ASCII letters allowed by the PNG spec.
+    NonAlphabetic { byte: u8, index: usize },
+    /// The input was not valid UTF-8.
+    InvalidUtf8,
+}
+
+impl Display for ChunkTypeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChunkTypeError::WrongLength { found } => {
+                write!(f, "chunk type must be exactly 4 bytes, found {found}")
+            }
+            ChunkTypeError::NonAlphabetic { byte, index } => {
+                write!(
+                    f,
+                    "chunk type byte {byte:#04x} at index {index} is not an ASCII letter"
+                )
+            }
+            ChunkTypeError::InvalidUtf8 => write!(f, "chunk type is not valid UTF-8"),
+        }
+    }
+}
+
+impl StdError for ChunkTypeError {}
+
+/// The four bit-flag properties the PNG spec derives from a chunk type's
+/// case pattern, bundled together for callers that want all of them at once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Properties {
+    pub is_critical: bool,
+    pub is_public: bool,
+    pub is_reserved_bit_valid: bool,
+    pub is_safe_to_copy: bool,
+}
+
+/// Standard PNG chunk types and the one-line description of their purpose,
+/// as defined by the PNG specification.
+const STANDARD_CHUNK_TYPES: &[(&str, &str)] = &[
+    ("IHDR", "Image header"),
+    ("PLTE", "Palette table"),
+    ("IDAT", "Image data"),
+    ("IEND", "Image trailer"),
+    ("tEXt", "Textual data"),
+    ("zTXt", "Compressed textual data"),
+    ("iTXt", "International textual data"),
+    ("bKGD", "Background color"),
+    ("cHRM", "Primary chromaticities and white point"),
+    ("gAMA", "Image gamma"),
+    ("hIST", "Image histogram"),
+    ("iCCP", "Embedded ICC profile"),
+    ("pHYs", "Physical pixel dimensions"),
+    ("sBIT", "Significant bits"),
+    ("sPLT", "Suggested palette"),
+    ("sRGB", "Standard RGB color space"),
+    ("tIME", "Image last-modification time"),
+    ("tRNS", "Transparency"),
+];
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
 pub struct ChunkType {
     chunk: [u8; 4],
 }
@@ -16,7 +81,7 @@ impl ChunkType {
         ChunkType { chunk }
     }
 
-    fn bytes(&self) -> [u8; 4] {
+    pub fn bytes(&self) -> [u8; 4] {
         self.chunk
     }
 
@@ -24,78 +89,106 @@ impl ChunkType {
         self.chunk[2].is_ascii_uppercase()
     }
 
-    fn is_valid(&self) -> bool {
+    pub fn is_valid(&self) -> bool {
         let condition1 = self.is_reserved_bit_valid();
         let condition2 = self.is_alphabetic();
         condition1 && condition2
     }
 
-    fn is_critical(&self) -> bool {
+    pub fn is_critical(&self) -> bool {
         self.chunk[0].is_ascii_uppercase()
     }
 
-    fn is_public(&self) -> bool {
+    pub fn is_public(&self) -> bool {
         self.chunk[1].is_ascii_uppercase()
     }
 
-    fn is_safe_to_copy(&self) -> bool {
+    pub fn is_safe_to_copy(&self) -> bool {
         self.chunk[3].is_ascii_lowercase()
     }
 
     fn is_alphabetic(&self) -> bool {
-        for byte in self.chunk {
-            if !byte.is_ascii_alphabetic() {
-                return false;
-            }
+        self.first_non_alphabetic().is_none()
+    }
+
+    /// Returns the index of the first byte that is not an ASCII letter, if any.
+    fn first_non_alphabetic(&self) -> Option<usize> {
+        self.chunk.iter().position(|byte| !byte.is_ascii_alphabetic())
+    }
+
+    /// Bundles the four bit-flag properties derived from this chunk type's
+    /// case pattern into a single value.
+    pub fn properties(&self) -> Properties {
+        Properties {
+            is_critical: self.is_critical(),
+            is_public: self.is_public(),
+            is_reserved_bit_valid: self.is_reserved_bit_valid(),
+            is_safe_to_copy: self.is_safe_to_copy(),
         }
-        true
+    }
+
+    /// Returns `true` if this is one of the chunk types defined by the PNG
+    /// specification (IHDR, IDAT, tEXt, ...), as opposed to a private or
+    /// application-specific type.
+    pub fn is_standard(&self) -> bool {
+        self.description().is_some()
+    }
+
+    /// Returns a short description of this chunk type's purpose if it is
+    /// one of the chunk types defined by the PNG specification.
+    pub fn description(&self) -> Option<&'static str> {
+        let name = self.to_string();
+        STANDARD_CHUNK_TYPES
+            .iter()
+            .find(|(type_name, _)| *type_name == name)
+            .map(|(_, description)| *description)
     }
 }
 
 impl TryFrom<[u8; 4]> for ChunkType {
-    type Error = Error;
+    type Error = ChunkTypeError;
 
-    fn try_from(value: [u8; 4]) -> Result<Self> {
+    fn try_from(value: [u8; 4]) -> std::result::Result<Self, ChunkTypeError> {
         let new_chunk = ChunkType::new(value);
-        if new_chunk.is_alphabetic() {
-            Ok(new_chunk)
-        } else {
-            Err("Chunk isn't valid".into())
+        match new_chunk.first_non_alphabetic() {
+            None => Ok(new_chunk),
+            Some(index) => Err(ChunkTypeError::NonAlphabetic {
+                byte: value[index],
+                index,
+            }),
         }
     }
 }
-impl Eq for ChunkType {}
+impl PartialOrd for ChunkType {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
 
-impl PartialEq for ChunkType {
-    fn eq(&self, other: &Self) -> bool {
-        for (byte, other) in self.chunk.iter().zip(other.chunk.iter()) {
-            if byte != other {
-                return false;
-            }
-        }
-        true
+impl Ord for ChunkType {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.chunk.cmp(&other.chunk)
     }
 }
 
 impl FromStr for ChunkType {
-    type Err = Error;
-
-    fn from_str(s: &str) -> Result<Self> {
-        let new_chunk: &[u8] = s.as_bytes();
-        let new_chunk: &[u8; 4] = new_chunk.try_into().unwrap();
-        let new_chunk: ChunkType = ChunkType::new(*new_chunk);
-        if new_chunk.is_alphabetic() {
-            Ok(new_chunk)
-        } else {
-            Err("Chunk is invalid".into())
-        }
+    type Err = ChunkTypeError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, ChunkTypeError> {
+        let bytes = s.as_bytes();
+        let chunk: [u8; 4] = bytes
+            .try_into()
+            .map_err(|_| ChunkTypeError::WrongLength { found: bytes.len() })?;
+        ChunkType::try_from(chunk)
     }
 }
 
 impl Display for ChunkType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let string: String = String::from_utf8(self.chunk.to_vec()).unwrap();
-        write!(f, "{string}")
+        for &byte in &self.chunk {
+            write!(f, "{}", byte as char)?;
+        }
+        Ok(())
     }
 }
 
@@ -196,4 +289,71 @@ mod tests {
         let _chunk_string = format!("{}", chunk_type_1);
         let _are_chunks_equal = chunk_type_1 == chunk_type_2;
     }
+
+    #[test]
+    pub fn test_chunk_type_from_str_wrong_length() {
+        let err = ChunkType::from_str("Ru").unwrap_err();
+        assert!(matches!(err, ChunkTypeError::WrongLength { found: 2 }));
+    }
+
+    #[test]
+    pub fn test_chunk_type_from_bytes_non_alphabetic() {
+        let err = ChunkType::try_from([82, 117, 49, 116]).unwrap_err();
+        assert!(matches!(
+            err,
+            ChunkTypeError::NonAlphabetic {
+                byte: 49,
+                index: 2
+            }
+        ));
+    }
+
+    #[test]
+    pub fn test_chunk_type_error_does_not_panic() {
+        assert!(ChunkType::from_str("").is_err());
+        assert!(ChunkType::from_str("TooLong").is_err());
+    }
+
+    #[test]
+    pub fn test_standard_chunk_type_is_standard() {
+        let chunk = ChunkType::from_str("IHDR").unwrap();
+        assert!(chunk.is_standard());
+        assert_eq!(chunk.description(), Some("Image header"));
+    }
+
+    #[test]
+    pub fn test_non_standard_chunk_type_is_not_standard() {
+        let chunk = ChunkType::from_str("RuSt").unwrap();
+        assert!(!chunk.is_standard());
+        assert_eq!(chunk.description(), None);
+    }
+
+    #[test]
+    pub fn test_chunk_type_properties() {
+        let chunk = ChunkType::from_str("RuSt").unwrap();
+        assert_eq!(
+            chunk.properties(),
+            Properties {
+                is_critical: true,
+                is_public: false,
+                is_reserved_bit_valid: true,
+                is_safe_to_copy: true,
+            }
+        );
+    }
+
+    #[test]
+    pub fn test_chunk_type_can_be_used_as_map_key() {
+        let mut chunks_by_type = std::collections::HashMap::new();
+        chunks_by_type.insert(ChunkType::from_str("IHDR").unwrap(), 1);
+        chunks_by_type.insert(ChunkType::from_str("IDAT").unwrap(), 2);
+        assert_eq!(chunks_by_type.get(&ChunkType::from_str("IHDR").unwrap()), Some(&1));
+    }
+
+    #[test]
+    pub fn test_chunk_type_ord() {
+        let a = ChunkType::from_str("IDAT").unwrap();
+        let b = ChunkType::from_str("IHDR").unwrap();
+        assert!(a < b);
+    }
 }
@@ -0,0 +1,4 @@
+pub mod chunk;
+pub mod chunk_type;
+pub mod png;
+pub mod text_chunk;
@@ -0,0 +1,257 @@
+use std::convert::TryFrom;
+use std::fmt::Display;
+
+use crate::chunk::Chunk;
+use crate::chunk_type::{Error, Result};
+
+/// An in-memory representation of a PNG file: the 8-byte file signature
+/// followed by an ordered sequence of chunks.
+#[derive(Debug)]
+pub struct Png {
+    chunks: Vec<Chunk>,
+}
+
+impl Png {
+    pub const STANDARD_HEADER: [u8; 8] = [137, 80, 78, 71, 13, 10, 26, 10];
+
+    pub fn from_chunks(chunks: Vec<Chunk>) -> Png {
+        Png { chunks }
+    }
+
+    pub fn append_chunk(&mut self, chunk: Chunk) {
+        self.chunks.push(chunk);
+    }
+
+    pub fn remove_first_chunk(&mut self, chunk_type: &str) -> Result<Chunk> {
+        let index = self
+            .chunks
+            .iter()
+            .position(|chunk| chunk.chunk_type().to_string() == chunk_type)
+            .ok_or_else(|| -> Error { format!("No chunk of type {chunk_type} found").into() })?;
+
+        Ok(self.chunks.remove(index))
+    }
+
+    pub fn header(&self) -> &[u8; 8] {
+        &Self::STANDARD_HEADER
+    }
+
+    pub fn chunks(&self) -> &[Chunk] {
+        &self.chunks
+    }
+
+    pub fn chunk_by_type(&self, chunk_type: &str) -> Option<&Chunk> {
+        self.chunks
+            .iter()
+            .find(|chunk| chunk.chunk_type().to_string() == chunk_type)
+    }
+
+    pub fn as_bytes(&self) -> Vec<u8> {
+        Self::STANDARD_HEADER
+            .iter()
+            .copied()
+            .chain(self.chunks.iter().flat_map(Chunk::as_bytes))
+            .collect()
+    }
+}
+
+impl TryFrom<&[u8]> for Png {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() < Self::STANDARD_HEADER.len() {
+            return Err("PNG data is shorter than the standard header".into());
+        }
+
+        let (header, mut rest) = bytes.split_at(Self::STANDARD_HEADER.len());
+        if header != Self::STANDARD_HEADER {
+            return Err("PNG data does not start with the standard header".into());
+        }
+
+        let mut chunks = Vec::new();
+        while !rest.is_empty() {
+            if rest.len() < 8 {
+                return Err("Trailing bytes do not form a complete chunk".into());
+            }
+            let length = u32::from_be_bytes(rest[0..4].try_into()?) as usize;
+            let chunk_end = 4 + 4 + length + 4;
+            if rest.len() < chunk_end {
+                return Err("Chunk is missing data or a CRC".into());
+            }
+
+            let (chunk_bytes, remainder) = rest.split_at(chunk_end);
+            chunks.push(Chunk::try_from(chunk_bytes)?);
+            rest = remainder;
+        }
+
+        Ok(Png::from_chunks(chunks))
+    }
+}
+
+impl Display for Png {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Png {{")?;
+        for chunk in &self.chunks {
+            write!(f, "  {chunk},")?;
+        }
+        write!(f, "}}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chunk_type::ChunkType;
+    use std::str::FromStr;
+
+    fn testing_chunks() -> Vec<Chunk> {
+        vec![
+            chunk_from_strings("FrSt", "I am the first chunk").unwrap(),
+            chunk_from_strings("miDl", "I am another chunk").unwrap(),
+            chunk_from_strings("LASt", "I am the last chunk").unwrap(),
+        ]
+    }
+
+    fn chunk_from_strings(chunk_type: &str, data: &str) -> Result<Chunk> {
+        let chunk_type = ChunkType::from_str(chunk_type)?;
+        let data: Vec<u8> = data.bytes().collect();
+        Ok(Chunk::new(chunk_type, data))
+    }
+
+    fn testing_png() -> Png {
+        let chunks = testing_chunks();
+        Png::from_chunks(chunks)
+    }
+
+    #[test]
+    fn test_from_chunks() {
+        let chunks = testing_chunks();
+        let png = Png::from_chunks(chunks);
+        assert_eq!(png.chunks().len(), 3);
+    }
+
+    #[test]
+    fn test_valid_from_bytes() {
+        let chunk_bytes: Vec<u8> = testing_chunks()
+            .into_iter()
+            .flat_map(|chunk| chunk.as_bytes())
+            .collect();
+
+        let bytes: Vec<u8> = Png::STANDARD_HEADER
+            .iter()
+            .chain(chunk_bytes.iter())
+            .copied()
+            .collect();
+
+        let png = Png::try_from(bytes.as_ref());
+
+        assert!(png.is_ok());
+    }
+
+    #[test]
+    fn test_invalid_header() {
+        let chunk_bytes: Vec<u8> = testing_chunks()
+            .into_iter()
+            .flat_map(|chunk| chunk.as_bytes())
+            .collect();
+
+        let bytes: Vec<u8> = [13, 80, 78, 71, 13, 10, 26, 10]
+            .iter()
+            .chain(chunk_bytes.iter())
+            .copied()
+            .collect();
+
+        let png = Png::try_from(bytes.as_ref());
+
+        assert!(png.is_err());
+    }
+
+    #[test]
+    fn test_invalid_chunk() {
+        let mut chunk_bytes: Vec<u8> = testing_chunks()
+            .into_iter()
+            .flat_map(|chunk| chunk.as_bytes())
+            .collect();
+
+        *chunk_bytes.last_mut().unwrap() ^= 0xFF;
+
+        let bytes: Vec<u8> = Png::STANDARD_HEADER
+            .iter()
+            .chain(chunk_bytes.iter())
+            .copied()
+            .collect();
+
+        let png = Png::try_from(bytes.as_ref());
+
+        assert!(png.is_err());
+    }
+
+    #[test]
+    pub fn test_list_chunks() {
+        let png = testing_png();
+        let chunks = png.chunks();
+        assert_eq!(chunks.len(), 3);
+    }
+
+    #[test]
+    pub fn test_chunk_by_type() {
+        let png = testing_png();
+        let chunk = png.chunk_by_type("FrSt").unwrap();
+        assert_eq!(chunk.chunk_type().to_string(), String::from("FrSt"));
+        assert_eq!(chunk.data_as_string().unwrap(), "I am the first chunk".to_string());
+    }
+
+    #[test]
+    pub fn test_append_chunk() {
+        let mut png = testing_png();
+        png.append_chunk(chunk_from_strings("TeSt", "Message").unwrap());
+        let chunk = png.chunk_by_type("TeSt").unwrap();
+        assert_eq!(chunk.chunk_type().to_string(), String::from("TeSt"));
+        assert_eq!(chunk.data_as_string().unwrap(), "Message".to_string());
+    }
+
+    #[test]
+    pub fn test_remove_chunk() {
+        let mut png = testing_png();
+        png.append_chunk(chunk_from_strings("TeSt", "Message").unwrap());
+        png.remove_first_chunk("TeSt").unwrap();
+        let chunk = png.chunk_by_type("TeSt");
+        assert!(chunk.is_none());
+    }
+
+    #[test]
+    pub fn test_remove_missing_chunk_is_err() {
+        let mut png = testing_png();
+        assert!(png.remove_first_chunk("TeSt").is_err());
+    }
+
+    #[test]
+    pub fn test_as_bytes() {
+        let png = testing_png();
+        let actual = png.as_bytes();
+        let expected: Vec<u8> = Png::STANDARD_HEADER
+            .iter()
+            .copied()
+            .chain(testing_chunks().into_iter().flat_map(|chunk| chunk.as_bytes()))
+            .collect();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    pub fn test_png_trait_impls() {
+        let chunk_bytes: Vec<u8> = testing_chunks()
+            .into_iter()
+            .flat_map(|chunk| chunk.as_bytes())
+            .collect();
+
+        let bytes: Vec<u8> = Png::STANDARD_HEADER
+            .iter()
+            .chain(chunk_bytes.iter())
+            .copied()
+            .collect();
+
+        let png: Png = TryFrom::try_from(bytes.as_ref()).unwrap();
+
+        let _png_string = format!("{png}");
+    }
+}
@@ -0,0 +1,254 @@
+use std::error::Error as StdError;
+use std::fmt;
+use std::fmt::Display;
+use std::io::{Read, Write};
+
+use flate2::read::ZlibDecoder;
+use flate2::write::ZlibEncoder;
+use flate2::Compression;
+
+/// Errors produced while building or parsing a PNG text chunk payload
+/// (`tEXt`, `zTXt` or `iTXt`).
+#[derive(Debug)]
+pub enum TextChunkError {
+    /// A keyword was empty or longer than the 79 bytes allowed by the spec.
+    KeywordLength { found: usize },
+    /// A keyword or text field contained a character outside Latin-1.
+    InvalidLatin1,
+    /// The payload was missing a required `\0` separator between fields.
+    MissingNullSeparator,
+    /// A text field was not valid UTF-8.
+    InvalidUtf8,
+    /// The text could not be zlib-deflated or inflated.
+    Compression(std::io::Error),
+}
+
+impl Display for TextChunkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TextChunkError::KeywordLength { found } => {
+                write!(f, "keyword must be 1-79 bytes, found {found}")
+            }
+            TextChunkError::InvalidLatin1 => {
+                write!(f, "field contains a character outside Latin-1")
+            }
+            TextChunkError::MissingNullSeparator => {
+                write!(f, "payload is missing a null separator between fields")
+            }
+            TextChunkError::InvalidUtf8 => write!(f, "text field is not valid UTF-8"),
+            TextChunkError::Compression(err) => write!(f, "zlib error: {err}"),
+        }
+    }
+}
+
+impl StdError for TextChunkError {}
+
+/// Encoders and decoders for the PNG textual data chunks (`tEXt`, `zTXt`,
+/// `iTXt`), the spec-compliant home for hiding a message in a PNG.
+pub struct TextChunk;
+
+impl TextChunk {
+    /// Builds a `tEXt` payload: `keyword\0text`, both Latin-1.
+    pub fn text(keyword: &str, text: &str) -> Result<Vec<u8>, TextChunkError> {
+        let keyword = to_latin1(keyword)?;
+        validate_keyword_length(&keyword)?;
+        let text = to_latin1(text)?;
+
+        Ok(keyword
+            .into_iter()
+            .chain(std::iter::once(0))
+            .chain(text)
+            .collect())
+    }
+
+    /// Builds a `zTXt` payload: `keyword\0` + compression method `0` +
+    /// zlib-deflated Latin-1 text.
+    pub fn compressed(keyword: &str, text: &str) -> Result<Vec<u8>, TextChunkError> {
+        let keyword = to_latin1(keyword)?;
+        validate_keyword_length(&keyword)?;
+        let text = to_latin1(text)?;
+
+        let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+        encoder
+            .write_all(&text)
+            .map_err(TextChunkError::Compression)?;
+        let compressed = encoder.finish().map_err(TextChunkError::Compression)?;
+
+        Ok(keyword
+            .into_iter()
+            .chain(std::iter::once(0))
+            .chain(std::iter::once(0)) // compression method: 0 = zlib/deflate
+            .chain(compressed)
+            .collect())
+    }
+
+    /// Builds an uncompressed `iTXt` payload: `keyword\0` + compression
+    /// flag/method bytes + `language_tag\0translated_keyword\0text`, with
+    /// the keyword in Latin-1 and the remaining fields in UTF-8.
+    pub fn international(
+        keyword: &str,
+        lang_tag: &str,
+        translated_keyword: &str,
+        text: &str,
+    ) -> Result<Vec<u8>, TextChunkError> {
+        let keyword = to_latin1(keyword)?;
+        validate_keyword_length(&keyword)?;
+
+        Ok(keyword
+            .into_iter()
+            .chain(std::iter::once(0))
+            .chain(std::iter::once(0)) // compression flag: 0 = uncompressed
+            .chain(std::iter::once(0)) // compression method: 0 = zlib/deflate
+            .chain(lang_tag.bytes())
+            .chain(std::iter::once(0))
+            .chain(translated_keyword.bytes())
+            .chain(std::iter::once(0))
+            .chain(text.bytes())
+            .collect())
+    }
+
+    /// Parses a `tEXt` payload into `(keyword, text)`.
+    pub fn parse_text(data: &[u8]) -> Result<(String, String), TextChunkError> {
+        let (keyword, text) = split_on_null(data)?;
+        validate_keyword_length(keyword)?;
+        Ok((from_latin1(keyword), from_latin1(text)))
+    }
+
+    /// Parses a `zTXt` payload into `(keyword, text)`, inflating the
+    /// zlib-compressed text.
+    pub fn parse_compressed(data: &[u8]) -> Result<(String, String), TextChunkError> {
+        let (keyword, rest) = split_on_null(data)?;
+        validate_keyword_length(keyword)?;
+
+        let compressed_text = rest
+            .get(1..)
+            .ok_or(TextChunkError::MissingNullSeparator)?;
+
+        let mut decoder = ZlibDecoder::new(compressed_text);
+        let mut text = Vec::new();
+        decoder
+            .read_to_end(&mut text)
+            .map_err(TextChunkError::Compression)?;
+
+        Ok((from_latin1(keyword), from_latin1(&text)))
+    }
+
+    /// Parses an `iTXt` payload into `(keyword, language_tag,
+    /// translated_keyword, text)`. Compressed `iTXt` payloads are not
+    /// supported.
+    pub fn parse_international(
+        data: &[u8],
+    ) -> Result<(String, String, String, String), TextChunkError> {
+        let (keyword, rest) = split_on_null(data)?;
+        validate_keyword_length(keyword)?;
+
+        let rest = rest.get(2..).ok_or(TextChunkError::MissingNullSeparator)?;
+        let (lang_tag, rest) = split_on_null(rest)?;
+        let (translated_keyword, text) = split_on_null(rest)?;
+
+        Ok((
+            from_latin1(keyword),
+            String::from_utf8(lang_tag.to_vec()).map_err(|_| TextChunkError::InvalidUtf8)?,
+            String::from_utf8(translated_keyword.to_vec())
+                .map_err(|_| TextChunkError::InvalidUtf8)?,
+            String::from_utf8(text.to_vec()).map_err(|_| TextChunkError::InvalidUtf8)?,
+        ))
+    }
+}
+
+fn validate_keyword_length(keyword: &[u8]) -> Result<(), TextChunkError> {
+    if keyword.is_empty() || keyword.len() > 79 {
+        Err(TextChunkError::KeywordLength {
+            found: keyword.len(),
+        })
+    } else {
+        Ok(())
+    }
+}
+
+/// Splits `data` on the first `\0`, as used to separate the keyword from
+/// the remainder of every text chunk payload.
+fn split_on_null(data: &[u8]) -> Result<(&[u8], &[u8]), TextChunkError> {
+    let index = data
+        .iter()
+        .position(|&byte| byte == 0)
+        .ok_or(TextChunkError::MissingNullSeparator)?;
+    Ok((&data[..index], &data[index + 1..]))
+}
+
+fn to_latin1(s: &str) -> Result<Vec<u8>, TextChunkError> {
+    s.chars()
+        .map(|c| u8::try_from(c as u32).map_err(|_| TextChunkError::InvalidLatin1))
+        .collect()
+}
+
+fn from_latin1(bytes: &[u8]) -> String {
+    bytes.iter().map(|&byte| byte as char).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_text_round_trip() {
+        let payload = TextChunk::text("Author", "Jane Doe").unwrap();
+        let (keyword, text) = TextChunk::parse_text(&payload).unwrap();
+        assert_eq!(keyword, "Author");
+        assert_eq!(text, "Jane Doe");
+    }
+
+    #[test]
+    fn test_compressed_round_trip() {
+        let payload = TextChunk::compressed("Comment", "a secret message").unwrap();
+        let (keyword, text) = TextChunk::parse_compressed(&payload).unwrap();
+        assert_eq!(keyword, "Comment");
+        assert_eq!(text, "a secret message");
+    }
+
+    #[test]
+    fn test_international_round_trip() {
+        let payload =
+            TextChunk::international("Title", "en", "Title", "a secret message").unwrap();
+        let (keyword, lang_tag, translated_keyword, text) =
+            TextChunk::parse_international(&payload).unwrap();
+        assert_eq!(keyword, "Title");
+        assert_eq!(lang_tag, "en");
+        assert_eq!(translated_keyword, "Title");
+        assert_eq!(text, "a secret message");
+    }
+
+    #[test]
+    fn test_empty_keyword_is_err() {
+        assert!(matches!(
+            TextChunk::text("", "text"),
+            Err(TextChunkError::KeywordLength { found: 0 })
+        ));
+    }
+
+    #[test]
+    fn test_keyword_too_long_is_err() {
+        let keyword = "a".repeat(80);
+        assert!(matches!(
+            TextChunk::text(&keyword, "text"),
+            Err(TextChunkError::KeywordLength { found: 80 })
+        ));
+    }
+
+    #[test]
+    fn test_non_latin1_keyword_is_err() {
+        assert!(matches!(
+            TextChunk::text("キーワード", "text"),
+            Err(TextChunkError::InvalidLatin1)
+        ));
+    }
+
+    #[test]
+    fn test_missing_null_separator_is_err() {
+        let data = b"NoSeparatorHere";
+        assert!(matches!(
+            TextChunk::parse_text(data),
+            Err(TextChunkError::MissingNullSeparator)
+        ));
+    }
+}